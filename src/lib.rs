@@ -1,15 +1,30 @@
 //! # Quick Start
 //!
-//! Contained within this module are two functions:
+//! Contained within this module are the following functions:
 //!   * `is_witness`
 //!   * `is_prime`
+//!   * `find_witness`
+//!   * `is_prime_bpsw`
+//!   * `factorize`
+//!   * `primes_in`
+//!   * `next_prime`
 //!
 //! The function `is_witness` performs a single iteration of the Miller-Rabin
 //! primality test.
 //!
-//! On the other hand, `is_prime` is a routine that performs the Miller-Rabin
-//! primality test a given number of times in parallel, exiting as soon as the iterator
-//! encounters a witness for the compositeness of the tested integer.
+//! `is_prime` performs the Miller-Rabin primality test a given number of
+//! times in parallel, exiting as soon as the iterator encounters a witness
+//! for the compositeness of the tested integer. `find_witness` runs the
+//! same test but returns the witness that proved compositeness (if any) as
+//! a `PrimalityResult` instead of discarding it.
+//!
+//! `is_prime_bpsw` runs the Baillie-PSW test, a base-2 strong Miller-Rabin
+//! round combined with a strong Lucas test, for callers who want a single
+//! deterministic-in-practice check rather than `k` random rounds.
+//!
+//! `factorize` decomposes an integer into its prime factors, and
+//! `primes_in`/`next_prime` generate primes over a range or from a
+//! starting point, both built on the primality tests above.
 
 extern crate num_bigint as bigint;
 extern crate num_integer as integer;
@@ -18,10 +33,11 @@ extern crate rand;
 extern crate rayon;
 
 use {
-    bigint::{BigUint, RandBigInt, ToBigUint},
+    bigint::{BigInt, BigUint, RandBigInt, ToBigInt, ToBigUint},
+    integer::Integer,
     rayon::prelude::*,
     std::iter::repeat_with,
-    traits::{One, Zero},
+    traits::{One, ToPrimitive, Zero},
 };
 
 macro_rules! biguint {
@@ -44,6 +60,94 @@ fn decompose(n: &BigUint) -> (BigUint, BigUint) {
     (d, r)
 }
 
+/// Compute `a * b mod m` for native `u64` operands, falling back to a
+/// widening `u128` multiply only when the product would overflow `u64`.
+fn mod_mul(a: u64, b: u64, m: u64) -> u64 {
+    a.checked_mul(b)
+        .map(|p| p % m)
+        .unwrap_or_else(|| ((a as u128 * b as u128) % m as u128) as u64)
+}
+
+/// Compute `a * a mod m`, using a plain `u64` multiply when `a` is small
+/// enough that it can't overflow.
+fn mod_sqr(a: u64, m: u64) -> u64 {
+    if a < 1 << 32 {
+        (a * a) % m
+    } else {
+        mod_mul(a, a, m)
+    }
+}
+
+/// Compute `base^exp mod m` via square-and-multiply, entirely in registers.
+fn mod_exp(base: u64, exp: u64, m: u64) -> u64 {
+    let mut result: u64 = 1 % m;
+    let mut base = base % m;
+    let mut exp = exp;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_sqr(base, m);
+        exp >>= 1;
+    }
+
+    result
+}
+
+fn decompose_u64(n: u64) -> (u64, u32) {
+    let mut d = n - 1;
+    let mut r = 0;
+
+    while d.is_multiple_of(2) {
+        d /= 2;
+        r += 1;
+    }
+
+    (d, r)
+}
+
+/// Smallest deterministic witness set proven sufficient below each
+/// threshold, in increasing order. The last entry, the full 12-base set,
+/// covers every `n` up to `u64::MAX`.
+const WITNESS_TIERS: &[(u64, &[u64])] = &[
+    (2_047, &[2]),
+    (1_373_653, &[2, 3]),
+    (25_326_001, &[2, 3, 5]),
+    (3_215_031_751, &[2, 3, 5, 7]),
+    (2_152_302_898_747, &[2, 3, 5, 7, 11]),
+    (3_474_749_660_383, &[2, 3, 5, 7, 11, 13]),
+    (341_550_071_728_321, &[2, 3, 5, 7, 11, 13, 17]),
+    (3_825_123_056_546_413_051, &[2, 3, 5, 7, 11, 13, 17, 19, 23]),
+    (u64::MAX, &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]),
+];
+
+/// Pick the smallest deterministic witness set known to be sufficient for
+/// `n`, via a binary search over `WITNESS_TIERS`.
+fn witness_bases(n: u64) -> &'static [u64] {
+    let idx = WITNESS_TIERS.partition_point(|&(threshold, _)| threshold <= n);
+    WITNESS_TIERS[idx.min(WITNESS_TIERS.len() - 1)].1
+}
+
+fn miller_rabin_u64(a: u64, n: u64, d: u64, r: u32) -> bool {
+    let n_minus_one = n - 1;
+    let mut x = mod_exp(a, d, n);
+
+    if x == 1 || x == n_minus_one {
+        return false;
+    }
+
+    for _ in 1..r {
+        x = mod_sqr(x, n);
+
+        if x == n_minus_one {
+            return false;
+        }
+    }
+
+    true
+}
+
 fn miller_rabin(a: &BigUint, n: &BigUint, d: &BigUint, r: &BigUint) -> bool {
     let n_minus_one: BigUint = n - 1u8;
     let mut x = a.modpow(d, n);
@@ -67,6 +171,127 @@ fn miller_rabin(a: &BigUint, n: &BigUint, d: &BigUint, r: &BigUint) -> bool {
     true
 }
 
+/// Compute the Jacobi symbol `(a/n)` for an odd `n`.
+fn jacobi_symbol(a: &BigInt, n: &BigUint) -> i32 {
+    let mut n: BigInt = n.to_bigint().unwrap();
+    let mut a: BigInt = a.mod_floor(&n);
+    let mut t = 1;
+
+    while !a.is_zero() {
+        while a.is_even() {
+            a /= 2;
+            let r = (&n % 8u8).to_u8().unwrap();
+            if r == 3 || r == 5 {
+                t = -t;
+            }
+        }
+
+        std::mem::swap(&mut a, &mut n);
+
+        if (&a % 4u8).to_u8().unwrap() == 3 && (&n % 4u8).to_u8().unwrap() == 3 {
+            t = -t;
+        }
+
+        a = a.mod_floor(&n);
+    }
+
+    if n.is_one() {
+        t
+    } else {
+        0
+    }
+}
+
+/// Select Lucas parameters by Selfridge's method: scan `D` over
+/// 5, -7, 9, -11, 13, ... until the Jacobi symbol `(D/n) = -1`, then
+/// return `(D, Q)` with `Q = (1 - D) / 4`; `P` is always 1.
+fn select_d(n: &BigUint) -> (BigInt, BigInt) {
+    let mut d: i64 = 5;
+
+    loop {
+        let d_big = BigInt::from(d);
+
+        if jacobi_symbol(&d_big, n) == -1 {
+            let q = (BigInt::from(1) - &d_big) / BigInt::from(4);
+            return (d_big, q);
+        }
+
+        d = if d > 0 { -(d + 2) } else { -(d - 2) };
+    }
+}
+
+/// Reduce `x` into the range `[0, n)`.
+fn mod_n(x: &BigInt, n: &BigInt) -> BigInt {
+    x.mod_floor(n)
+}
+
+/// Divide `x` by 2 modulo the odd `n`, reducing `x` into `[0, n)` first.
+fn mod_half(x: &BigInt, n: &BigInt) -> BigInt {
+    let y = mod_n(x, n);
+
+    if y.is_even() {
+        y / 2
+    } else {
+        (y + n) / 2
+    }
+}
+
+/// Test whether `n` is a strong Lucas probable prime using Selfridge's
+/// method to select parameters.
+fn strong_lucas_prp(n: &BigUint) -> bool {
+    let root = n.sqrt();
+    if &root * &root == *n {
+        return false;
+    }
+
+    let (d, q) = select_d(n);
+    let n_int = n.to_bigint().unwrap();
+
+    let mut delta: BigUint = n + 1u8;
+    let ref two = biguint!(2);
+    let mut s = 0u32;
+
+    while &delta % two == Zero::zero() {
+        delta /= two;
+        s += 1;
+    }
+
+    let bits: Vec<bool> = delta.to_str_radix(2).chars().map(|c| c == '1').collect();
+
+    let mut u: BigInt = One::one();
+    let mut v: BigInt = One::one();
+    let mut qk = q.clone();
+
+    for &bit in &bits[1..] {
+        u = mod_n(&(&u * &v), &n_int);
+        v = mod_n(&(&v * &v - &qk - &qk), &n_int);
+        qk = mod_n(&(&qk * &qk), &n_int);
+
+        if bit {
+            let next_u = mod_half(&(&u + &v), &n_int);
+            let next_v = mod_half(&(&d * &u + &v), &n_int);
+            u = next_u;
+            v = next_v;
+            qk = mod_n(&(&qk * &q), &n_int);
+        }
+    }
+
+    if u.is_zero() {
+        return true;
+    }
+
+    for _ in 0..s {
+        if v.is_zero() {
+            return true;
+        }
+
+        v = mod_n(&(&v * &v - &qk - &qk), &n_int);
+        qk = mod_n(&(&qk * &qk), &n_int);
+    }
+
+    false
+}
+
 /// Test whether an integer `a` is a witness for the compositeness of `n`.
 ///
 /// NOTE: This function fails if `a < 2` or `n < 3`.
@@ -91,6 +316,77 @@ pub fn is_witness<T: ToBigUint>(a: &T, n: &T) -> Option<bool> {
     Some(miller_rabin(a, n, d, r))
 }
 
+/// The result of a probabilistic primality test: `n` passed every round,
+/// a specific base was found that proves it composite, or `n <= 1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrimalityResult {
+    /// No witness for compositeness was found in the bases tried.
+    ProbablyPrime,
+    /// `witness` is a base for which the Miller-Rabin test proves `n`
+    /// composite.
+    Composite {
+        /// The base that triggered `miller_rabin`.
+        witness: BigUint,
+    },
+    /// `n <= 1`, which is composite by definition; no base was tested.
+    TrivialComposite,
+}
+
+/// Test whether an integer `n` is likely prime using the Miller-Rabin
+/// primality test, returning the witness that proved compositeness (if
+/// any) instead of discarding it.
+///
+/// # Examples
+///
+/// ```
+/// use miller_rabin::{find_witness, PrimalityResult};
+///
+/// // Mersenne Prime (2^31 - 1)
+/// let n: u64 = 0x7FFF_FFFF;
+/// assert_eq!(find_witness(&n, 16), PrimalityResult::ProbablyPrime);
+/// ```
+pub fn find_witness<T: ToBigUint>(n: &T, k: usize) -> PrimalityResult {
+    let ref n = biguint!(n);
+
+    if n <= &One::one() {
+        return PrimalityResult::TrivialComposite;
+    } else if n <= &biguint!(3) {
+        return PrimalityResult::ProbablyPrime;
+    }
+
+    let n_minus_one: BigUint = n - 1u8;
+    let (ref d, ref r) = decompose(n);
+
+    if n <= &biguint!(0xFFFF_FFFF_FFFF_FFFFu64) {
+        // `n` fits in a `u64`, so run the deterministic bases through the
+        // native fast path instead of repeatedly heap-allocating `BigUint`s.
+        let n = n.to_u64().unwrap();
+        let n_minus_one = n - 1;
+        let (d, r) = decompose_u64(n);
+        return match witness_bases(n)
+            .par_iter()
+            .filter(|&&a| a < n_minus_one)
+            .find_any(|&&a| miller_rabin_u64(a, n, d, r))
+        {
+            Some(&a) => PrimalityResult::Composite {
+                witness: biguint!(a),
+            },
+            None => PrimalityResult::ProbablyPrime,
+        };
+    }
+
+    let mut rng = rand::thread_rng();
+    let samples: Vec<BigUint> = repeat_with(|| rng.gen_biguint(n_minus_one.bits()))
+        .filter(|m| m < &n_minus_one)
+        .take(k)
+        .collect();
+
+    match samples.par_iter().find_any(|&a| miller_rabin(a, n, d, r)) {
+        Some(a) => PrimalityResult::Composite { witness: a.clone() },
+        None => PrimalityResult::ProbablyPrime,
+    }
+}
+
 /// Test whether an integer `n` is likely prime using the Miller-Rabin primality test.
 ///
 /// # Examples
@@ -106,33 +402,335 @@ pub fn is_witness<T: ToBigUint>(a: &T, n: &T) -> Option<bool> {
 /// assert!(is_prime(&n, 16));
 /// ```
 pub fn is_prime<T: ToBigUint>(n: &T, k: usize) -> bool {
+    find_witness(n, k) == PrimalityResult::ProbablyPrime
+}
+
+/// Test whether an integer `n` is prime using the Baillie–PSW primality
+/// test: a single base-2 strong Miller-Rabin test combined with a strong
+/// Lucas probable-prime test. No composite is known to pass both, making
+/// this far more trustworthy for large `n` than a handful of random
+/// Miller-Rabin rounds.
+///
+/// # Examples
+///
+/// ```
+/// use miller_rabin::is_prime_bpsw;
+///
+/// // Mersenne Prime (2^31 - 1)
+/// let n: u64 = 0x7FFF_FFFF;
+/// assert!(is_prime_bpsw(&n));
+/// ```
+pub fn is_prime_bpsw<T: ToBigUint>(n: &T) -> bool {
     let ref n = biguint!(n);
-    let n_minus_one: BigUint = n - 1u8;
-    let (ref d, ref r) = decompose(n);
+    let ref two = biguint!(2);
 
     if n <= &One::one() {
         return false;
-    } else if n <= &biguint!(3) {
+    } else if n == two {
         return true;
-    } else if n <= &biguint!(0xFFFF_FFFF_FFFF_FFFFu64) {
-        let samples: Vec<u8> = vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
-        return samples
-            .par_iter()
-            .filter(|&&m| biguint!(m) < n_minus_one)
-            .find_any(|&&a| miller_rabin(&biguint!(a), n, d, r))
-            .is_none();
+    } else if n % two == Zero::zero() {
+        return false;
     }
 
+    let mut d: BigUint = n - 1u8;
+    let mut r: BigUint = Zero::zero();
+    while (&d % two).is_zero() {
+        d /= two;
+        r += 1u8;
+    }
+
+    if miller_rabin(two, n, &d, &r) {
+        return false;
+    }
+
+    strong_lucas_prp(n)
+}
+
+const SMALL_PRIMES: &[u64] = &[
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+/// Split a composite `n` into a nontrivial factor using Pollard's rho with
+/// Brent's cycle detection: iterate `x -> (x^2 + c) mod n`, accumulating
+/// the product of `|x_i - x_j|` differences and taking a single `gcd` with
+/// `n` per batch of iterations to amortize its cost. When the batch gcd
+/// comes back as `n` itself, steps back through the batch one gcd at a
+/// time, and restarts with a new `c` only if that also yields `n`.
+fn pollard_rho_brent(n: &BigUint) -> BigUint {
     let mut rng = rand::thread_rng();
-    let samples: Vec<BigUint> = repeat_with(|| rng.gen_biguint(n_minus_one.bits()))
-        .filter(|m| m < &n_minus_one)
-        .take(k)
-        .collect();
+    let one: BigUint = One::one();
+    let batch = 128;
+
+    loop {
+        let c = rng.gen_biguint_below(n);
+        let mut x = rng.gen_biguint_below(n);
+        let mut y = x.clone();
+        let mut ys = y.clone();
+        let mut q = one.clone();
+        let mut g = one.clone();
+        let mut r = 1usize;
+
+        while g == one {
+            x = y.clone();
+            for _ in 0..r {
+                y = (&y * &y + &c) % n;
+            }
+
+            let mut k = 0;
+            while k < r && g == one {
+                ys = y.clone();
+                let steps = std::cmp::min(batch, r - k);
+
+                for _ in 0..steps {
+                    y = (&y * &y + &c) % n;
+                    let diff = if x > y { &x - &y } else { &y - &x };
+                    q = (&q * diff) % n;
+                }
+
+                g = q.gcd(n);
+                k += steps;
+            }
+
+            r *= 2;
+        }
+
+        if &g == n {
+            loop {
+                ys = (&ys * &ys + &c) % n;
+                let diff = if x > ys { &x - &ys } else { &ys - &x };
+                g = diff.gcd(n);
+
+                if g > one {
+                    break;
+                }
+            }
+        }
+
+        if &g != n {
+            return g;
+        }
+    }
+}
+
+/// Recursively split a cofactor that has no small prime factors down to
+/// primes, merging matching factors and parallelizing independent splits
+/// with the rayon pool.
+fn split(n: &BigUint) -> Vec<(BigUint, u32)> {
+    if is_prime(n, 16) {
+        return vec![(n.clone(), 1)];
+    }
+
+    let factor = pollard_rho_brent(n);
+    let cofactor = n / &factor;
+
+    let (left, right) = rayon::join(|| split(&factor), || split(&cofactor));
+
+    let mut factors = left;
+    for (p, e) in right {
+        if let Some(existing) = factors.iter_mut().find(|(q, _)| *q == p) {
+            existing.1 += e;
+        } else {
+            factors.push((p, e));
+        }
+    }
 
-    samples
-        .par_iter()
-        .find_any(|&a| miller_rabin(a, n, d, r))
-        .is_none()
+    factors
+}
+
+/// Factor `n` into its prime power decomposition as `(prime, exponent)`
+/// pairs, sorted by increasing prime.
+///
+/// Small prime factors are stripped by trial division first; any
+/// remaining cofactor is checked with [`is_prime`] and, if composite,
+/// split with Pollard's rho.
+///
+/// # Panics
+///
+/// Panics if `n` is zero, which has no prime factorization.
+///
+/// # Examples
+///
+/// ```
+/// use miller_rabin::factorize;
+/// use num_bigint::ToBigUint;
+///
+/// let factors = factorize(&360u64);
+/// let expected: Vec<_> = vec![(2u8, 3), (3, 2), (5, 1)]
+///     .into_iter()
+///     .map(|(p, e)| (p.to_biguint().unwrap(), e))
+///     .collect();
+/// assert_eq!(factors, expected);
+/// ```
+pub fn factorize<T: ToBigUint>(n: &T) -> Vec<(BigUint, u32)> {
+    let mut n = biguint!(n);
+    assert!(!n.is_zero(), "factorize: 0 has no prime factorization");
+
+    let mut factors = Vec::new();
+
+    for &p in SMALL_PRIMES {
+        let p = biguint!(p);
+        let mut count = 0u32;
+
+        while (&n % &p).is_zero() {
+            n /= &p;
+            count += 1;
+        }
+
+        if count > 0 {
+            factors.push((p, count));
+        }
+    }
+
+    if n > One::one() {
+        factors.append(&mut split(&n));
+    }
+
+    factors.sort_by(|a, b| a.0.cmp(&b.0));
+    factors
+}
+
+/// The product of the first four primes; candidates are pre-filtered
+/// modulo this wheel to skip obvious multiples of 2, 3, 5, and 7 before
+/// they ever reach the Miller-Rabin core.
+const WHEEL: u64 = 2 * 3 * 5 * 7;
+
+/// The residues mod `WHEEL` that are coprime to it, in increasing order.
+const WHEEL_RESIDUES: &[u64] = &[
+    1, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97, 101,
+    103, 107, 109, 113, 121, 127, 131, 137, 139, 143, 149, 151, 157, 163, 167, 169, 173, 179, 181,
+    187, 191, 193, 197, 199, 209,
+];
+
+/// Lazily enumerates candidates at or above `start` (and, if `end` is
+/// `Some`, below it) that are either one of the wheel's own prime factors
+/// or coprime to it, generating one wheel block of residues at a time
+/// instead of materializing the whole range up front.
+struct WheelCandidates {
+    start: BigUint,
+    end: Option<BigUint>,
+    small_idx: usize,
+    base: BigUint,
+    residue_idx: usize,
+}
+
+impl WheelCandidates {
+    fn new(start: BigUint, end: Option<BigUint>) -> Self {
+        let ref wheel = biguint!(WHEEL);
+        let base = (&start / wheel) * wheel;
+
+        WheelCandidates {
+            start,
+            end,
+            small_idx: 0,
+            base,
+            residue_idx: 0,
+        }
+    }
+}
+
+impl Iterator for WheelCandidates {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        const SMALL_PRIMES: [u64; 4] = [2, 3, 5, 7];
+
+        while self.small_idx < SMALL_PRIMES.len() {
+            let p = biguint!(SMALL_PRIMES[self.small_idx]);
+            self.small_idx += 1;
+
+            let below_end = match &self.end {
+                Some(end) => &p < end,
+                None => true,
+            };
+
+            if p >= self.start && below_end {
+                return Some(p);
+            }
+        }
+
+        loop {
+            if self.residue_idx == WHEEL_RESIDUES.len() {
+                self.residue_idx = 0;
+                self.base += biguint!(WHEEL);
+            }
+
+            if let Some(end) = &self.end {
+                if &self.base >= end {
+                    return None;
+                }
+            }
+
+            let r = WHEEL_RESIDUES[self.residue_idx];
+            self.residue_idx += 1;
+            let candidate = &self.base + biguint!(r);
+
+            if let Some(end) = &self.end {
+                if &candidate >= end {
+                    return None;
+                }
+            }
+
+            if candidate >= self.start {
+                return Some(candidate);
+            }
+        }
+    }
+}
+
+/// Enumerate the primes in `range`, lazily generating wheel-filtered
+/// candidates and testing them in parallel across the rayon pool.
+///
+/// # Examples
+///
+/// ```
+/// use miller_rabin::primes_in;
+/// use num_bigint::ToBigUint;
+/// use rayon::prelude::*;
+///
+/// let lo = 10u32.to_biguint().unwrap();
+/// let hi = 30u32.to_biguint().unwrap();
+/// let mut primes: Vec<_> = primes_in(lo..hi).collect();
+/// primes.sort();
+/// assert_eq!(
+///     primes,
+///     vec![11u32, 13, 17, 19, 23, 29]
+///         .into_iter()
+///         .map(|p| p.to_biguint().unwrap())
+///         .collect::<Vec<_>>()
+/// );
+/// ```
+pub fn primes_in(range: std::ops::Range<BigUint>) -> impl ParallelIterator<Item = BigUint> {
+    WheelCandidates::new(range.start, Some(range.end))
+        .par_bridge()
+        .filter(is_prime_bpsw)
+}
+
+/// Find the smallest prime strictly greater than `n`, lazily generating
+/// wheel-filtered candidates in increasing batches and testing each batch
+/// in parallel across the rayon pool, stopping at the first batch that
+/// holds a prime.
+///
+/// # Examples
+///
+/// ```
+/// use miller_rabin::next_prime;
+///
+/// let n: u64 = 10;
+/// assert_eq!(next_prime(&n), 11u8.into());
+/// ```
+pub fn next_prime<T: ToBigUint>(n: &T) -> BigUint {
+    let start: BigUint = biguint!(n) + 1u8;
+    let mut candidates = WheelCandidates::new(start, None);
+
+    loop {
+        let batch: Vec<BigUint> = candidates.by_ref().take(WHEEL_RESIDUES.len()).collect();
+
+        if let Some(p) = batch.into_par_iter().find_first(is_prime_bpsw) {
+            return p;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -190,6 +788,145 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_bpsw_prime() -> io::Result<()> {
+        let prime: BigUint =
+            BigUint::parse_bytes(b"170141183460469231731687303715884105727", 10).unwrap();
+
+        assert!(is_prime_bpsw(&prime));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bpsw_composite() -> io::Result<()> {
+        let composite: u64 = 0xFFFF_FFFF_FFFF_FFFF;
+        assert!(!is_prime_bpsw(&composite));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bpsw_perfect_square() -> io::Result<()> {
+        let square: u64 = 9 * 9;
+        assert!(!is_prime_bpsw(&square));
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_prime() -> io::Result<()> {
+        let n: u64 = 10;
+        assert_eq!(next_prime(&n), 11u8.to_biguint().unwrap());
+
+        let mersenne_prime: u64 = 0x7FFF_FFFF;
+        assert_eq!(
+            next_prime(&mersenne_prime),
+            2147483659u64.to_biguint().unwrap()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_primes_in() -> io::Result<()> {
+        let lo = 10u32.to_biguint().unwrap();
+        let hi = 30u32.to_biguint().unwrap();
+        let mut primes: Vec<BigUint> = primes_in(lo..hi).collect();
+        primes.sort();
+
+        let expected: Vec<BigUint> = vec![11u32, 13, 17, 19, 23, 29]
+            .into_iter()
+            .map(|p| p.to_biguint().unwrap())
+            .collect();
+
+        assert_eq!(primes, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_witness_bases_tiers() -> io::Result<()> {
+        assert_eq!(witness_bases(2_046), &[2]);
+        assert_eq!(witness_bases(2_047), &[2, 3]);
+        assert_eq!(witness_bases(3_215_031_750), &[2, 3, 5, 7]);
+        assert_eq!(witness_bases(3_215_031_751), &[2, 3, 5, 7, 11]);
+        assert_eq!(
+            witness_bases(u64::MAX),
+            &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_witness_prime() -> io::Result<()> {
+        let prime: u64 = 0x7FFF_FFFF;
+        assert_eq!(find_witness(&prime, K), PrimalityResult::ProbablyPrime);
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_witness_composite() -> io::Result<()> {
+        let composite: u64 = 0xFFFF_FFFF_FFFF_FFFF;
+        match find_witness(&composite, K) {
+            PrimalityResult::Composite { witness } => assert!(witness > Zero::zero()),
+            other => panic!("expected a witness for a composite number, got {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_witness_trivial() -> io::Result<()> {
+        assert_eq!(find_witness(&0u64, K), PrimalityResult::TrivialComposite);
+        assert_eq!(find_witness(&1u64, K), PrimalityResult::TrivialComposite);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "0 has no prime factorization")]
+    fn test_factorize_zero() {
+        factorize(&0u64);
+    }
+
+    #[test]
+    fn test_factorize_small() -> io::Result<()> {
+        let factors = factorize(&360u64);
+        let expected: Vec<(BigUint, u32)> = vec![(2u8, 3), (3, 2), (5, 1)]
+            .into_iter()
+            .map(|(p, e)| (p.to_biguint().unwrap(), e))
+            .collect();
+
+        assert_eq!(factors, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_factorize_prime() -> io::Result<()> {
+        let factors = factorize(&0x7FFF_FFFFu64);
+        assert_eq!(
+            factors,
+            vec![(0x7FFF_FFFFu64.to_biguint().unwrap(), 1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_factorize_semiprime() -> io::Result<()> {
+        let p: u64 = 999_999_937;
+        let q: u64 = 1_000_000_007;
+        let n = p.to_biguint().unwrap() * q.to_biguint().unwrap();
+
+        let factors = factorize(&n);
+        let expected: Vec<(BigUint, u32)> = vec![(p, 1), (q, 1)]
+            .into_iter()
+            .map(|(p, e)| (p.to_biguint().unwrap(), e))
+            .collect();
+
+        assert_eq!(factors, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mod_exp() -> io::Result<()> {
+        assert_eq!(mod_exp(4, 13, 497), 445);
+        Ok(())
+    }
+
     #[test]
     fn test_big_composite() -> io::Result<()> {
         let prime: BigUint =